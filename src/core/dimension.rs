@@ -13,4 +13,38 @@ pub trait Dimension {
     ///
     /// returns: usize total number of element in this tensor
     fn numel(&self) -> usize;
+
+    /// Return the number of elements to skip, on each axis, to move to the next element
+    /// along that axis.
+    ///
+    /// returns: &[usize] strides, one per axis, expressed in number of elements
+    fn strides(&self) -> &[usize];
+
+    /// Indicate whether this tensor's `strides` describe a dense, row-major layout of
+    /// `shape`, i.e. whether iterating its backing buffer linearly visits elements in
+    /// logical order with no gaps.
+    ///
+    /// returns: bool true when the tensor is dense row-major, false for a strided view
+    fn is_contiguous(&self) -> bool {
+        let shape = self.shape();
+        let strides = self.strides();
+
+        let mut expected = 1;
+        for (&dim, &stride) in shape.iter().zip(strides.iter()).rev() {
+            if dim != 1 && stride != expected {
+                return false;
+            }
+            expected *= dim;
+        }
+
+        true
+    }
+
+    /// Materialize a dense, row-major copy of this tensor, copying the backing buffer
+    /// only when it is not already contiguous.
+    ///
+    /// returns: Self a contiguous tensor with the same shape, sharing `self`'s buffer when no copy was needed
+    fn to_contiguous(&self) -> Self
+    where
+        Self: Sized;
 }
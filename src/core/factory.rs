@@ -1,4 +1,4 @@
-use num_traits::Num;
+use num_traits::{Num, NumCast};
 
 pub trait Factory<T: Num + Copy> {
     /// Allocate a new multi-dimensional array with all elements filled with `value`.
@@ -28,4 +28,74 @@ pub trait Factory<T: Num + Copy> {
     ///
     /// returns: Self
     fn ones(shape: &[usize]) -> Self;
+
+    /// Allocate a new 1-D tensor with values `[start, start + step, ...)`, stopping before
+    /// `stop`. Mirrors NumPy's `arange`: the result is empty when the range is degenerate
+    /// (e.g. `step` has the wrong sign for `start..stop`).
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: first value of the sequence
+    /// * `stop`: exclusive upper (or lower, for a negative `step`) bound of the sequence
+    /// * `step`: increment between consecutive values
+    ///
+    /// returns: Self a 1-D tensor of length `ceil((stop - start) / step)`
+    fn arange(start: T, stop: T, step: T) -> Self
+    where
+        T: PartialOrd + NumCast;
+
+    /// Allocate a new 1-D tensor of `n` values evenly spaced between `start` and `stop`,
+    /// inclusive of both endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `start`: first value of the sequence
+    /// * `stop`: last value of the sequence
+    /// * `n`: number of values to generate
+    ///
+    /// returns: Self a 1-D tensor of length `n`
+    fn linspace(start: T, stop: T, n: usize) -> Self
+    where
+        T: NumCast;
+
+    /// Allocate a new `n`x`n` identity matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `n`: number of rows (and columns) of the matrix
+    ///
+    /// returns: Self an `n`x`n` tensor with ones on the diagonal and zeroes elsewhere
+    fn eye(n: usize) -> Self;
+
+    /// Allocate a new multi-dimensional array with elements drawn independently from the
+    /// uniform distribution over `[low, high)`. Gated behind the `rand` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `low`: inclusive lower bound of the distribution
+    /// * `high`: exclusive upper bound of the distribution
+    /// * `shape`: The shape of the multi-dimensional array
+    ///
+    /// returns: Self
+    #[cfg(feature = "rand")]
+    fn random_uniform(low: T, high: T, shape: &[usize]) -> Self
+    where
+        T: rand::distributions::uniform::SampleUniform;
+
+    /// Allocate a new multi-dimensional array with elements drawn independently from the
+    /// normal distribution with mean `mean` and standard deviation `std`. Gated behind the
+    /// `rand` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `mean`: mean of the distribution
+    /// * `std`: standard deviation of the distribution
+    /// * `shape`: The shape of the multi-dimensional array
+    ///
+    /// returns: Self
+    #[cfg(feature = "rand")]
+    fn random_normal(mean: T, std: T, shape: &[usize]) -> Self
+    where
+        T: rand_distr::num_traits::Float,
+        rand_distr::StandardNormal: rand_distr::Distribution<T>;
 }
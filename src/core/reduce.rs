@@ -0,0 +1,63 @@
+use num_traits::{Float, Num, NumCast};
+
+/// Axis-wise reductions over a tensor: the reduction axes are collapsed (or kept as
+/// size-1 axes when `keep_dims` is set) while every other axis is preserved.
+pub trait Reduce<T: Num + Copy> {
+    /// Sum `self` over `axes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes`: the axes to reduce over
+    /// * `keep_dims`: when true, reduced axes are kept with size 1 instead of being dropped
+    ///
+    /// returns: Self
+    fn sum(&self, axes: &[usize], keep_dims: bool) -> Self;
+
+    /// Average `self` over `axes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes`: the axes to reduce over
+    /// * `keep_dims`: when true, reduced axes are kept with size 1 instead of being dropped
+    ///
+    /// returns: Self
+    fn mean(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: NumCast;
+
+    /// Compute the standard deviation of `self` over `axes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes`: the axes to reduce over
+    /// * `keep_dims`: when true, reduced axes are kept with size 1 instead of being dropped
+    ///
+    /// returns: Self
+    fn std(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: Float;
+
+    /// Take the minimum of `self` over `axes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes`: the axes to reduce over
+    /// * `keep_dims`: when true, reduced axes are kept with size 1 instead of being dropped
+    ///
+    /// returns: Self
+    fn min(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: PartialOrd;
+
+    /// Take the maximum of `self` over `axes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes`: the axes to reduce over
+    /// * `keep_dims`: when true, reduced axes are kept with size 1 instead of being dropped
+    ///
+    /// returns: Self
+    fn max(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: PartialOrd;
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::io;
+
+/// Maps a Rust element type to the dtype tag safetensors stores in its header
+/// (e.g. `"F32"`, `"I64"`).
+pub trait SafeTensorsDtype {
+    const DTYPE: &'static str;
+}
+
+impl SafeTensorsDtype for f32 {
+    const DTYPE: &'static str = "F32";
+}
+
+impl SafeTensorsDtype for f64 {
+    const DTYPE: &'static str = "F64";
+}
+
+impl SafeTensorsDtype for i32 {
+    const DTYPE: &'static str = "I32";
+}
+
+impl SafeTensorsDtype for u32 {
+    const DTYPE: &'static str = "U32";
+}
+
+impl SafeTensorsDtype for i64 {
+    const DTYPE: &'static str = "I64";
+}
+
+impl SafeTensorsDtype for u64 {
+    const DTYPE: &'static str = "U64";
+}
+
+/// Errors that can occur while reading or writing the safetensors format.
+#[derive(Debug)]
+pub enum SafeTensorsError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    UnknownTensor(String),
+    DtypeMismatch { expected: String, found: String },
+    InvalidDataOffsets {
+        name: String,
+        start: usize,
+        end: usize,
+        expected_len: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for SafeTensorsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafeTensorsError::Io(err) => write!(f, "safetensors I/O error: {err}"),
+            SafeTensorsError::Json(err) => write!(f, "safetensors header error: {err}"),
+            SafeTensorsError::UnknownTensor(name) => {
+                write!(f, "no tensor named '{name}' in safetensors file")
+            }
+            SafeTensorsError::DtypeMismatch { expected, found } => write!(
+                f,
+                "dtype mismatch: expected '{expected}', found '{found}' in safetensors file"
+            ),
+            SafeTensorsError::InvalidDataOffsets {
+                name,
+                start,
+                end,
+                expected_len,
+                available,
+            } => write!(
+                f,
+                "corrupt safetensors header: tensor '{name}' declares data_offsets \
+                 [{start}, {end}), which do not span the expected {expected_len} bytes \
+                 within the file's {available} bytes of data"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SafeTensorsError {}
+
+impl From<io::Error> for SafeTensorsError {
+    fn from(err: io::Error) -> Self {
+        SafeTensorsError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SafeTensorsError {
+    fn from(err: serde_json::Error) -> Self {
+        SafeTensorsError::Json(err)
+    }
+}
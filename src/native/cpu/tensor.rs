@@ -1,12 +1,70 @@
-use crate::core::{Dimension, Factory};
-use num_traits::Num;
+use crate::core::safetensors::{SafeTensorsDtype, SafeTensorsError};
+use crate::core::{Dimension, Factory, Reduce};
+use num_traits::{Float, Num, NumCast, One, Zero};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Write};
 use std::mem::size_of;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A single entry of a safetensors header: dtype tag, logical shape, and the half-open
+/// byte range `[start, end)` of this tensor's data within the file's data block.
+#[derive(Serialize, Deserialize)]
+struct SafeTensorsHeaderEntry {
+    dtype: String,
+    shape: Vec<usize>,
+    data_offsets: (usize, usize),
+}
 
 #[derive(Debug, Clone)]
 pub struct Tensor<T: Num + Sized + Copy> {
-    data: Vec<T>,
+    data: Arc<Vec<T>>,
     shape: SmallVec<[usize; 5]>,
+    strides: SmallVec<[usize; 5]>,
+    offset: usize,
+}
+
+/// Compute the row-major (C-order) strides for `shape`: the innermost axis has stride 1
+/// and each preceding axis's stride is the product of all the faster-moving axes' extents.
+fn row_major_strides(shape: &[usize]) -> SmallVec<[usize; 5]> {
+    let mut strides = SmallVec::from_elem(1usize, shape.len());
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+
+    strides
+}
+
+/// Enumerate every multi-index of `shape` in row-major order (innermost axis fastest).
+/// Yields exactly one empty index for a rank-0 `shape`.
+fn each_index(shape: &[usize]) -> impl Iterator<Item = SmallVec<[usize; 5]>> + '_ {
+    let numel: usize = shape.iter().product();
+    let mut index = SmallVec::<[usize; 5]>::from_elem(0, shape.len());
+    let mut emitted = 0usize;
+
+    std::iter::from_fn(move || {
+        if emitted >= numel {
+            return None;
+        }
+
+        let current = index.clone();
+        emitted += 1;
+
+        for axis in (0..index.len()).rev() {
+            index[axis] += 1;
+            if index[axis] < shape[axis] {
+                break;
+            }
+            index[axis] = 0;
+        }
+
+        Some(current)
+    })
 }
 
 #[allow(dead_code)]
@@ -48,8 +106,10 @@ impl<T: Num + Sized + Copy> Factory<T> for Tensor<T> {
     fn fill(value: T, shape: &[usize]) -> Self {
         let numel = shape.iter().product();
         Tensor {
-            data: vec![value; numel],
+            data: Arc::new(vec![value; numel]),
+            strides: row_major_strides(shape),
             shape: SmallVec::from(shape),
+            offset: 0,
         }
     }
 
@@ -92,6 +152,122 @@ impl<T: Num + Sized + Copy> Factory<T> for Tensor<T> {
     fn ones(shape: &[usize]) -> Self {
         Self::fill(T::one(), shape)
     }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::native::cpu::Tensor;
+    /// use mdarray::core::Factory;
+    ///
+    /// let tensor = Tensor::<f32>::arange(0f32, 10f32, 2f32);
+    /// ```
+    fn arange(start: T, stop: T, step: T) -> Self
+    where
+        T: PartialOrd + NumCast,
+    {
+        let mut values = Vec::new();
+        let mut current = start;
+        while (step > T::zero() && current < stop) || (step < T::zero() && current > stop) {
+            values.push(current);
+            current = current + step;
+        }
+
+        let numel = values.len();
+        Tensor {
+            data: Arc::new(values),
+            strides: row_major_strides(&[numel]),
+            shape: SmallVec::from_slice(&[numel]),
+            offset: 0,
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::native::cpu::Tensor;
+    /// use mdarray::core::Factory;
+    ///
+    /// let tensor = Tensor::<f32>::linspace(0f32, 1f32, 5);
+    /// ```
+    fn linspace(start: T, stop: T, n: usize) -> Self
+    where
+        T: NumCast,
+    {
+        let values = if n <= 1 {
+            vec![start; n]
+        } else {
+            let step = (stop - start) / T::from(n - 1).expect("n - 1 must fit in T");
+            (0..n)
+                .map(|i| start + step * T::from(i).expect("index must fit in T"))
+                .collect()
+        };
+
+        Tensor {
+            data: Arc::new(values),
+            strides: row_major_strides(&[n]),
+            shape: SmallVec::from_slice(&[n]),
+            offset: 0,
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::native::cpu::Tensor;
+    /// use mdarray::core::Factory;
+    ///
+    /// let tensor = Tensor::<f32>::eye(3);
+    /// ```
+    fn eye(n: usize) -> Self {
+        let mut tensor = Self::zeros(&[n, n]);
+        let data = Arc::get_mut(&mut tensor.data).expect("freshly allocated tensor is unshared");
+        for i in 0..n {
+            data[i * n + i] = T::one();
+        }
+
+        tensor
+    }
+
+    #[cfg(feature = "rand")]
+    fn random_uniform(low: T, high: T, shape: &[usize]) -> Self
+    where
+        T: rand::distributions::uniform::SampleUniform,
+    {
+        use rand::distributions::{Distribution, Uniform};
+
+        let numel = shape.iter().product();
+        let distribution = Uniform::new(low, high);
+        let mut rng = rand::thread_rng();
+        let data = (0..numel).map(|_| distribution.sample(&mut rng)).collect();
+
+        Tensor {
+            data: Arc::new(data),
+            strides: row_major_strides(shape),
+            shape: SmallVec::from(shape),
+            offset: 0,
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    fn random_normal(mean: T, std: T, shape: &[usize]) -> Self
+    where
+        T: rand_distr::num_traits::Float,
+        rand_distr::StandardNormal: rand_distr::Distribution<T>,
+    {
+        use rand_distr::{Distribution, Normal};
+
+        let numel = shape.iter().product();
+        let distribution = Normal::new(mean, std).expect("std must be finite and non-negative");
+        let mut rng = rand::thread_rng();
+        let data = (0..numel).map(|_| distribution.sample(&mut rng)).collect();
+
+        Tensor {
+            data: Arc::new(data),
+            strides: row_major_strides(shape),
+            shape: SmallVec::from(shape),
+            offset: 0,
+        }
+    }
 }
 
 impl<T: Num + Sized + Copy> Dimension for Tensor<T> {
@@ -137,12 +313,727 @@ impl<T: Num + Sized + Copy> Dimension for Tensor<T> {
     fn numel(&self) -> usize {
         self.shape.iter().product()
     }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::native::cpu::Tensor;
+    /// use mdarray::core::{Factory, Dimension};
+    ///
+    /// let tensor = Tensor::<f32>::ones(&[2, 5]);
+    /// println!("Tensor's strides are {:?}", tensor.strides());
+    /// ```
+    fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    fn to_contiguous(&self) -> Self {
+        if self.offset == 0 && self.is_contiguous() {
+            return self.clone();
+        }
+
+        let data = self
+            .logical_offsets()
+            .map(|offset| self.data[offset])
+            .collect::<Vec<_>>();
+        Tensor {
+            data: Arc::new(data),
+            strides: row_major_strides(&self.shape),
+            shape: self.shape.clone(),
+            offset: 0,
+        }
+    }
+}
+
+impl<T: Num + Sized + Copy> Tensor<T> {
+    /// Compute the flat offset, into the backing buffer, of the element addressed by
+    /// `index`.
+    fn offset_of(&self, index: &[usize]) -> usize {
+        self.offset
+            + index
+                .iter()
+                .zip(self.strides.iter())
+                .map(|(&i, &stride)| i * stride)
+                .sum::<usize>()
+    }
+
+    /// Read the element at `index`, a multi-dimensional index with one component per axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: one coordinate per axis
+    ///
+    /// returns: Option<T> the element, or None when `index` is out of bounds for `shape`
+    pub fn get(&self, index: &[usize]) -> Option<T> {
+        if index.len() != self.shape.len()
+            || index.iter().zip(self.shape.iter()).any(|(&i, &dim)| i >= dim)
+        {
+            return None;
+        }
+
+        Some(self.data[self.offset_of(index)])
+    }
+
+    /// Write `value` at `index`, a multi-dimensional index with one component per axis.
+    ///
+    /// `Tensor` has copy-on-write value semantics, not NumPy/PyTorch-style aliasing: when
+    /// the backing buffer is shared with another `Tensor` (e.g. one produced by
+    /// [`Tensor::transpose`], [`Tensor::slice`] or [`Tensor::broadcast_to`]), this call
+    /// privately clones the buffer before writing, so the write is visible only through
+    /// `self` and never through the tensor(s) it was derived from or shares its buffer with.
+    ///
+    /// # Arguments
+    ///
+    /// * `index`: one coordinate per axis
+    /// * `value`: the value to write
+    pub fn set(&mut self, index: &[usize], value: T) {
+        assert_eq!(
+            index.len(),
+            self.shape.len(),
+            "set requires one coordinate per dimension"
+        );
+        assert!(
+            index.iter().zip(self.shape.iter()).all(|(&i, &dim)| i < dim),
+            "index out of bounds for shape {:?}",
+            self.shape
+        );
+
+        let offset = self.offset_of(index);
+        Arc::make_mut(&mut self.data)[offset] = value;
+    }
+
+    /// Convert a flat, logical (row-major over `shape`) offset into a multi-dimensional
+    /// index, by repeated divmod against `shape` from the innermost axis outward.
+    ///
+    /// # Arguments
+    ///
+    /// * `flat`: a logical offset in `0..self.numel()`
+    ///
+    /// returns: SmallVec<[usize; 5]> the corresponding multi-dimensional index
+    pub fn flat_to_index(&self, flat: usize) -> SmallVec<[usize; 5]> {
+        let mut index = SmallVec::<[usize; 5]>::from_elem(0, self.shape.len());
+        let mut remainder = flat;
+        for axis in (0..self.shape.len()).rev() {
+            index[axis] = remainder % self.shape[axis];
+            remainder /= self.shape[axis];
+        }
+
+        index
+    }
+
+    /// Iterate over every element of `self` in logical (shape) order, even when `self` is
+    /// a non-contiguous/strided view.
+    ///
+    /// returns: impl Iterator<Item = T>
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.logical_offsets().map(move |offset| self.data[offset])
+    }
+
+    /// Walk every element of `self` in logical (shape) order, yielding its flat offset into
+    /// the backing buffer. Works for non-contiguous/strided tensors by incrementing a
+    /// multi-index odometer, innermost axis first.
+    fn logical_offsets(&self) -> impl Iterator<Item = usize> + '_ {
+        let numel = self.numel();
+        let mut index = SmallVec::<[usize; 5]>::from_elem(0, self.shape.len());
+        let mut emitted = 0usize;
+
+        std::iter::from_fn(move || {
+            if emitted >= numel {
+                return None;
+            }
+
+            let offset = self.offset_of(&index);
+            emitted += 1;
+
+            for axis in (0..index.len()).rev() {
+                index[axis] += 1;
+                if index[axis] < self.shape[axis] {
+                    break;
+                }
+                index[axis] = 0;
+            }
+
+            Some(offset)
+        })
+    }
+
+    /// Return a permuted view over `self`, reordering its axes according to `axes` (a
+    /// permutation of `0..rank`). Shares the backing buffer with `self` for reads — no data
+    /// is copied. These are copy-on-write value semantics, not NumPy/PyTorch-style mutable
+    /// views: calling [`Tensor::set`] on the result copies the buffer on first write (see
+    /// [`Tensor::set`]) rather than mutating `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes`: the new order of the axes, e.g. `&[1, 0]` to transpose a matrix
+    ///
+    /// returns: Self a value sharing `self`'s buffer (until written to) with permuted shape and strides
+    pub fn transpose(&self, axes: &[usize]) -> Self {
+        assert_eq!(
+            axes.len(),
+            self.shape.len(),
+            "transpose requires one axis index per dimension"
+        );
+
+        Tensor {
+            data: Arc::clone(&self.data),
+            shape: axes.iter().map(|&a| self.shape[a]).collect(),
+            strides: axes.iter().map(|&a| self.strides[a]).collect(),
+            offset: self.offset,
+        }
+    }
+
+    /// Return a view over the sub-tensor described by `ranges`, one half-open range per
+    /// axis. Shares the backing buffer with `self` for reads — no data is copied. These are
+    /// copy-on-write value semantics, not NumPy/PyTorch-style mutable views: calling
+    /// [`Tensor::set`] on the result does not mutate `self` (see [`Tensor::set`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `ranges`: one half-open range per axis, bounding the slice along that axis
+    ///
+    /// returns: Self a value sharing `self`'s buffer (until written to), offset and shrunk to `ranges`
+    pub fn slice(&self, ranges: &[Range<usize>]) -> Self {
+        assert_eq!(
+            ranges.len(),
+            self.shape.len(),
+            "slice requires one range per dimension"
+        );
+        for (axis, (range, &dim)) in ranges.iter().zip(self.shape.iter()).enumerate() {
+            assert!(
+                range.start <= range.end && range.end <= dim,
+                "slice range {:?} out of bounds for axis {} of size {}",
+                range,
+                axis,
+                dim
+            );
+        }
+
+        let offset = self.offset
+            + ranges
+                .iter()
+                .zip(self.strides.iter())
+                .map(|(range, &stride)| range.start * stride)
+                .sum::<usize>();
+
+        Tensor {
+            data: Arc::clone(&self.data),
+            shape: ranges.iter().map(|range| range.len()).collect(),
+            strides: self.strides.clone(),
+            offset,
+        }
+    }
+
+    /// Return a tensor with the given `shape`, reusing `self`'s buffer when it is already
+    /// contiguous and starts at the beginning of that buffer, or materializing a dense copy
+    /// first when it does not.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: the new shape; must describe the same number of elements as `self`
+    ///
+    /// returns: Self a tensor addressing the same elements laid out as `shape`
+    pub fn reshape(&self, shape: &[usize]) -> Self {
+        assert_eq!(
+            self.numel(),
+            shape.iter().product::<usize>(),
+            "reshape cannot change the number of elements"
+        );
+
+        let contiguous = self.to_contiguous();
+        Tensor {
+            data: contiguous.data,
+            strides: row_major_strides(shape),
+            shape: SmallVec::from(shape),
+            offset: 0,
+        }
+    }
+
+    /// Return a view broadcasting `self` to `shape`, following NumPy-style broadcasting
+    /// rules for the *shape*: axes are aligned on their trailing dimensions and any axis of
+    /// size 1 is stretched by setting its stride to 0. The *mutation* semantics are not
+    /// NumPy/PyTorch-style, though: this shares `self`'s backing buffer only for reads —
+    /// these are copy-on-write values, so calling [`Tensor::set`] on the result does not
+    /// mutate `self` (see [`Tensor::set`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: the target shape, which must be broadcast-compatible with `self`'s shape
+    ///
+    /// returns: Self a value sharing `self`'s buffer (until written to) with the broadcast shape
+    pub fn broadcast_to(&self, shape: &[usize]) -> Self {
+        assert!(
+            shape.len() >= self.shape.len(),
+            "cannot broadcast to a shape with fewer axes"
+        );
+
+        let pad = shape.len() - self.shape.len();
+        let mut strides = SmallVec::<[usize; 5]>::from_elem(0, shape.len());
+        for i in 0..self.shape.len() {
+            let dim = self.shape[i];
+            let target = shape[pad + i];
+            assert!(
+                dim == target || dim == 1,
+                "shape mismatch when broadcasting axis {} ({} into {})",
+                i,
+                dim,
+                target
+            );
+            strides[pad + i] = if dim == target { self.strides[i] } else { 0 };
+        }
+
+        Tensor {
+            data: Arc::clone(&self.data),
+            shape: SmallVec::from(shape),
+            strides,
+            offset: self.offset,
+        }
+    }
+
+    /// Expand a `kept_index` (addressing only the axes absent from `axes`) into a full-rank
+    /// multi-index, with every reduced axis set to 0. Used to address a `keep_dims` output
+    /// tensor (whose reduced axes have size 1) from the group it was computed from.
+    fn kept_multi_index(&self, axes: &[usize], kept_index: &[usize]) -> SmallVec<[usize; 5]> {
+        let mut full = SmallVec::<[usize; 5]>::from_elem(0, self.shape.len());
+        let mut k = 0;
+        for axis in 0..self.shape.len() {
+            if !axes.contains(&axis) {
+                full[axis] = kept_index[k];
+                k += 1;
+            }
+        }
+
+        full
+    }
+
+    /// Reduction primitive: partitions the shape into axes kept in the output and axes
+    /// reduced over, then for each output element walks the corresponding input slice
+    /// accumulating `identity -> update -> finalize`. Parallelizes the outer loop over
+    /// output elements with rayon when there is more than one; falls back to a serial,
+    /// single-accumulator path when the whole tensor reduces to one scalar.
+    fn for_each_reduced_elt<A, I, U, F>(
+        &self,
+        axes: &[usize],
+        keep_dims: bool,
+        identity: I,
+        update: U,
+        finalize: F,
+    ) -> Tensor<T>
+    where
+        T: Send + Sync,
+        A: Send,
+        I: Fn(&[usize]) -> A + Sync,
+        U: Fn(A, T) -> A + Sync,
+        F: Fn(A, usize) -> T + Sync,
+    {
+        let rank = self.shape.len();
+        assert!(
+            axes.iter().all(|&a| a < rank),
+            "reduction axis out of bounds for rank {} tensor: {:?}",
+            rank,
+            axes
+        );
+
+        let reduced_axes: SmallVec<[usize; 5]> = (0..rank).filter(|a| axes.contains(a)).collect();
+        let reduced_shape: SmallVec<[usize; 5]> =
+            reduced_axes.iter().map(|&a| self.shape[a]).collect();
+        let reduced_count = reduced_shape.iter().product::<usize>().max(1);
+
+        let kept_shape: SmallVec<[usize; 5]> = (0..rank)
+            .filter(|a| !axes.contains(a))
+            .map(|a| self.shape[a])
+            .collect();
+        let out_shape: SmallVec<[usize; 5]> = if keep_dims {
+            (0..rank)
+                .map(|a| if axes.contains(&a) { 1 } else { self.shape[a] })
+                .collect()
+        } else {
+            kept_shape.clone()
+        };
+
+        let compute = |kept_index: &SmallVec<[usize; 5]>| -> T {
+            let mut full_index = self.kept_multi_index(axes, kept_index);
+            let mut acc = identity(kept_index);
+
+            if reduced_axes.is_empty() {
+                acc = update(acc, self.data[self.offset_of(&full_index)]);
+            } else {
+                for reduced_index in each_index(&reduced_shape) {
+                    for (i, &axis) in reduced_axes.iter().enumerate() {
+                        full_index[axis] = reduced_index[i];
+                    }
+                    acc = update(acc, self.data[self.offset_of(&full_index)]);
+                }
+            }
+
+            finalize(acc, reduced_count)
+        };
+
+        let kept_indices: Vec<_> = each_index(&kept_shape).collect();
+        let values: Vec<T> = if kept_indices.len() > 1 {
+            kept_indices.par_iter().map(compute).collect()
+        } else {
+            kept_indices.iter().map(compute).collect()
+        };
+
+        Tensor {
+            data: Arc::new(values),
+            strides: row_major_strides(&out_shape),
+            shape: out_shape,
+            offset: 0,
+        }
+    }
+}
+
+impl<T: Num + Sized + Copy + Send + Sync> Reduce<T> for Tensor<T> {
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::native::cpu::Tensor;
+    /// use mdarray::core::{Factory, Reduce};
+    ///
+    /// let tensor = Tensor::<f32>::ones(&[4, 16]);
+    /// let totals = tensor.sum(&[1], false);
+    /// ```
+    fn sum(&self, axes: &[usize], keep_dims: bool) -> Self {
+        self.for_each_reduced_elt(axes, keep_dims, |_| T::zero(), |acc, v| acc + v, |acc, _| acc)
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::native::cpu::Tensor;
+    /// use mdarray::core::{Factory, Reduce};
+    ///
+    /// let tensor = Tensor::<f32>::ones(&[4, 16]);
+    /// let averages = tensor.mean(&[1], false);
+    /// ```
+    fn mean(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: NumCast,
+    {
+        self.for_each_reduced_elt(
+            axes,
+            keep_dims,
+            |_| T::zero(),
+            |acc, v| acc + v,
+            |acc, count| acc / T::from(count).expect("reduced element count must fit in T"),
+        )
+    }
+
+    fn std(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: Float,
+    {
+        let mean = self.mean(axes, true);
+        self.for_each_reduced_elt(
+            axes,
+            keep_dims,
+            |kept_index| {
+                let mean_index = mean.kept_multi_index(axes, kept_index);
+                (T::zero(), mean.data[mean.offset_of(&mean_index)])
+            },
+            |(sum_sq, mean_value), v| (sum_sq + (v - mean_value) * (v - mean_value), mean_value),
+            |(sum_sq, _), count| {
+                (sum_sq / T::from(count).expect("reduced element count must fit in T")).sqrt()
+            },
+        )
+    }
+
+    fn min(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.for_each_reduced_elt(
+            axes,
+            keep_dims,
+            |_| None,
+            |acc: Option<T>, v| Some(match acc {
+                Some(a) if a < v => a,
+                _ => v,
+            }),
+            |acc, _| acc.expect("reduction over at least one element"),
+        )
+    }
+
+    fn max(&self, axes: &[usize], keep_dims: bool) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.for_each_reduced_elt(
+            axes,
+            keep_dims,
+            |_| None,
+            |acc: Option<T>, v| Some(match acc {
+                Some(a) if a > v => a,
+                _ => v,
+            }),
+            |acc, _| acc.expect("reduction over at least one element"),
+        )
+    }
+}
+
+impl<T: Num + Sized + Copy + SafeTensorsDtype> Tensor<T> {
+    /// Write `entries` to `path` in the safetensors format: a little-endian `u64` header
+    /// length, a JSON header mapping each name to `{dtype, shape, data_offsets}`, followed
+    /// by the raw contiguous bytes of every tensor back to back.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: destination file
+    /// * `entries`: the named tensors to write, in the order they are written to disk
+    ///
+    /// returns: Result<(), SafeTensorsError>
+    pub fn save_collection<P: AsRef<Path>>(
+        path: P,
+        entries: &[(&str, &Tensor<T>)],
+    ) -> Result<(), SafeTensorsError> {
+        let mut header = BTreeMap::new();
+        let mut payload = Vec::new();
+
+        for (name, tensor) in entries {
+            let contiguous = tensor.to_contiguous();
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    contiguous.data.as_ptr() as *const u8,
+                    contiguous.data.len() * size_of::<T>(),
+                )
+            };
+
+            let start = payload.len();
+            payload.extend_from_slice(bytes);
+
+            header.insert(
+                (*name).to_string(),
+                SafeTensorsHeaderEntry {
+                    dtype: T::DTYPE.to_string(),
+                    shape: contiguous.shape.to_vec(),
+                    data_offsets: (start, payload.len()),
+                },
+            );
+        }
+
+        let header_json = serde_json::to_vec(&header)?;
+        let mut file = File::create(path)?;
+        file.write_all(&(header_json.len() as u64).to_le_bytes())?;
+        file.write_all(&header_json)?;
+        file.write_all(&payload)?;
+
+        Ok(())
+    }
+
+    /// Write `self` to `path` in the safetensors format, stored under `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: destination file
+    /// * `name`: the name this tensor is stored under
+    ///
+    /// returns: Result<(), SafeTensorsError>
+    pub fn save<P: AsRef<Path>>(&self, path: P, name: &str) -> Result<(), SafeTensorsError> {
+        Self::save_collection(path, &[(name, self)])
+    }
+
+    /// Read the tensor named `name` out of the safetensors file at `path`, validating that
+    /// its stored dtype matches `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: source file
+    /// * `name`: the name of the tensor to read
+    ///
+    /// returns: Result<Tensor<T>, SafeTensorsError>
+    pub fn load<P: AsRef<Path>>(path: P, name: &str) -> Result<Tensor<T>, SafeTensorsError> {
+        let mut file = File::open(path)?;
+
+        let mut header_len_bytes = [0u8; 8];
+        file.read_exact(&mut header_len_bytes)?;
+        let header_len = u64::from_le_bytes(header_len_bytes) as usize;
+
+        let mut header_bytes = vec![0u8; header_len];
+        file.read_exact(&mut header_bytes)?;
+        let header: BTreeMap<String, SafeTensorsHeaderEntry> =
+            serde_json::from_slice(&header_bytes)?;
+
+        let entry = header
+            .get(name)
+            .ok_or_else(|| SafeTensorsError::UnknownTensor(name.to_string()))?;
+        if entry.dtype != T::DTYPE {
+            return Err(SafeTensorsError::DtypeMismatch {
+                expected: T::DTYPE.to_string(),
+                found: entry.dtype.clone(),
+            });
+        }
+
+        let mut payload = Vec::new();
+        file.read_to_end(&mut payload)?;
+        let (start, end) = entry.data_offsets;
+        let expected_len = entry.shape.iter().product::<usize>() * size_of::<T>();
+        if start > end || end > payload.len() || end - start != expected_len {
+            return Err(SafeTensorsError::InvalidDataOffsets {
+                name: name.to_string(),
+                start,
+                end,
+                expected_len,
+                available: payload.len(),
+            });
+        }
+        let bytes = &payload[start..end];
+
+        let mut tensor = Tensor::<T>::fill(T::zero(), &entry.shape);
+        let buffer =
+            Arc::get_mut(&mut tensor.data).expect("freshly allocated tensor is unshared");
+        for (i, slot) in buffer.iter_mut().enumerate() {
+            let element_bytes = &bytes[i * size_of::<T>()..(i + 1) * size_of::<T>()];
+            *slot = unsafe { std::ptr::read_unaligned(element_bytes.as_ptr() as *const T) };
+        }
+
+        Ok(tensor)
+    }
+}
+
+/// Implement `matmul` for a `gemm`-supported scalar type. `gemm` dispatches its micro-kernels
+/// per concrete type, so this is implemented per type rather than generically over `Num`.
+macro_rules! impl_matmul {
+    ($ty:ty) => {
+        impl Tensor<$ty> {
+            /// Multiply `self` by `rhs`, both at least 2-D, contracting `self`'s last axis
+            /// against `rhs`'s second-to-last axis. Leading ("batch") axes are broadcast
+            /// against each other the same way [`Tensor::broadcast_to`] does. Dispatches to
+            /// the `gemm` crate, parallelizing across batches and within each product.
+            ///
+            /// # Arguments
+            ///
+            /// * `rhs`: the right-hand operand; `rhs.shape()[..-2]` must be broadcast-compatible
+            ///   with `self.shape()[..-2]` and `rhs.shape()[-2]` must equal `self.shape()[-1]`
+            ///
+            /// returns: Tensor<$ty> shaped `[..batch, self.shape()[-2], rhs.shape()[-1]]`
+            pub fn matmul(&self, rhs: &Tensor<$ty>) -> Tensor<$ty> {
+                assert!(
+                    self.shape.len() >= 2 && rhs.shape.len() >= 2,
+                    "matmul requires tensors of rank 2 or higher"
+                );
+
+                let m = self.shape[self.shape.len() - 2];
+                let k = self.shape[self.shape.len() - 1];
+                let k_rhs = rhs.shape[rhs.shape.len() - 2];
+                let n = rhs.shape[rhs.shape.len() - 1];
+                assert_eq!(
+                    k, k_rhs,
+                    "matmul: inner dimensions must match ({k} != {k_rhs})"
+                );
+
+                let lhs_batch = &self.shape[..self.shape.len() - 2];
+                let rhs_batch = &rhs.shape[..rhs.shape.len() - 2];
+                let batch_rank = lhs_batch.len().max(rhs_batch.len());
+
+                let mut batch_shape = SmallVec::<[usize; 5]>::from_elem(1, batch_rank);
+                for axis in 0..batch_rank {
+                    let lhs_dim = lhs_batch
+                        .get(lhs_batch.len().wrapping_sub(1 + axis))
+                        .copied()
+                        .unwrap_or(1);
+                    let rhs_dim = rhs_batch
+                        .get(rhs_batch.len().wrapping_sub(1 + axis))
+                        .copied()
+                        .unwrap_or(1);
+                    assert!(
+                        lhs_dim == rhs_dim || lhs_dim == 1 || rhs_dim == 1,
+                        "matmul: batch dimensions are not broadcastable"
+                    );
+                    batch_shape[batch_rank - 1 - axis] = lhs_dim.max(rhs_dim);
+                }
+
+                let out_shape: SmallVec<[usize; 5]> =
+                    batch_shape.iter().copied().chain([m, n]).collect();
+                let mut out = Tensor::<$ty>::zeros(&out_shape);
+
+                if m == 0 || n == 0 {
+                    // `par_chunks_mut` below requires a non-zero chunk size; there is
+                    // nothing to multiply into an already-empty output.
+                    return out;
+                }
+
+                // Materialize only `self`/`rhs` themselves (a no-op when already
+                // contiguous) rather than the broadcast batch shape: batch axes that are
+                // size 1 are addressed with index 0 for every batch below, which is
+                // exactly the stride-0 broadcasting behaviour of `broadcast_to` without
+                // ever duplicating the underlying buffer.
+                let lhs = self.to_contiguous();
+                let rhs = rhs.to_contiguous();
+
+                let batch_index_into = |own_batch: &[usize], batch: &[usize]| -> SmallVec<[usize; 5]> {
+                    let pad = batch.len() - own_batch.len();
+                    own_batch
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &dim)| if dim == 1 { 0 } else { batch[pad + i] })
+                        .collect()
+                };
+
+                let out_buffer = Arc::get_mut(&mut out.data)
+                    .expect("freshly allocated tensor is unshared");
+
+                let batches: Vec<_> = each_index(&batch_shape).collect();
+                let matmul_batch = |batch: &SmallVec<[usize; 5]>, out_slice: &mut [$ty]| {
+                    let mut lhs_index = batch_index_into(lhs_batch, batch);
+                    lhs_index.push(0);
+                    lhs_index.push(0);
+                    let mut rhs_index = batch_index_into(rhs_batch, batch);
+                    rhs_index.push(0);
+                    rhs_index.push(0);
+                    let lhs_base = lhs.offset_of(&lhs_index);
+                    let rhs_base = rhs.offset_of(&rhs_index);
+
+                    unsafe {
+                        gemm::gemm(
+                            m,
+                            n,
+                            k,
+                            out_slice.as_mut_ptr(),
+                            1,
+                            n as isize,
+                            false,
+                            lhs.data[lhs_base..].as_ptr(),
+                            1,
+                            k as isize,
+                            rhs.data[rhs_base..].as_ptr(),
+                            1,
+                            n as isize,
+                            <$ty>::zero(),
+                            <$ty>::one(),
+                            false,
+                            false,
+                            false,
+                            gemm::Parallelism::Rayon(0),
+                        );
+                    }
+                };
+
+                if batches.len() > 1 {
+                    out_buffer
+                        .par_chunks_mut(m * n)
+                        .zip(batches.par_iter())
+                        .for_each(|(out_slice, batch)| matmul_batch(batch, out_slice));
+                } else {
+                    for batch in &batches {
+                        matmul_batch(batch, out_buffer);
+                    }
+                }
+
+                out
+            }
+        }
+    };
 }
 
+impl_matmul!(f32);
+impl_matmul!(f64);
+
 #[cfg(test)]
 mod tests {
     mod allocator {
-        use crate::core::Factory;
+        use crate::core::{Dimension, Factory};
         use crate::native::cpu::tensor::{DoubleTensor, FloatTensor};
 
         #[test]
@@ -171,6 +1062,30 @@ mod tests {
             let t = DoubleTensor::fill(5f64, &[4, 16]);
             assert!((t.data.iter().sum::<f64>() - 5f64 * 4f64 * 16f64).abs() < f64::EPSILON);
         }
+
+        #[test]
+        pub fn test_arange() {
+            let t = FloatTensor::arange(0f32, 10f32, 2f32);
+            assert_eq!(t.data.as_slice(), [0f32, 2f32, 4f32, 6f32, 8f32]);
+
+            let empty = FloatTensor::arange(10f32, 0f32, 2f32);
+            assert_eq!(empty.numel(), 0);
+        }
+
+        #[test]
+        pub fn test_linspace() {
+            let t = FloatTensor::linspace(0f32, 1f32, 5);
+            assert_eq!(t.data.as_slice(), [0f32, 0.25f32, 0.5f32, 0.75f32, 1f32]);
+        }
+
+        #[test]
+        pub fn test_eye() {
+            let t = FloatTensor::eye(3);
+            assert_eq!(
+                t.data.as_slice(),
+                [1f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 1f32]
+            );
+        }
     }
 
     mod dimension {
@@ -205,4 +1120,286 @@ mod tests {
             assert_eq!(t.numel(), (4 * 16));
         }
     }
+
+    mod views {
+        use crate::core::{Dimension, Factory};
+        use crate::native::cpu::tensor::FloatTensor;
+
+        #[test]
+        pub fn test_is_contiguous() {
+            let t = FloatTensor::zeros(&[4, 16]);
+            assert!(t.is_contiguous());
+            assert_eq!(t.strides(), [16_usize, 1_usize]);
+
+            let transposed = t.transpose(&[1, 0]);
+            assert_eq!(transposed.shape(), [16_usize, 4_usize]);
+            assert!(!transposed.is_contiguous());
+        }
+
+        #[test]
+        pub fn test_transpose_shares_buffer() {
+            let t = FloatTensor::fill(2f32, &[2, 3]);
+            let transposed = t.transpose(&[1, 0]);
+
+            assert!(std::sync::Arc::ptr_eq(&t.data, &transposed.data));
+            assert_eq!(transposed.shape(), [3_usize, 2_usize]);
+            assert_eq!(transposed.numel(), t.numel());
+            assert_eq!(transposed.to_contiguous().numel(), 6);
+        }
+
+        #[test]
+        pub fn test_slice() {
+            let t = FloatTensor::fill(1f32, &[4, 4]);
+            let sliced = t.slice(&[1..3, 0..2]);
+
+            assert_eq!(sliced.shape(), [2_usize, 2_usize]);
+            assert_eq!(sliced.numel(), 4);
+        }
+
+        #[test]
+        #[should_panic(expected = "out of bounds")]
+        pub fn test_slice_rejects_out_of_bounds_range() {
+            let t = FloatTensor::fill(1f32, &[4, 4]);
+            t.slice(&[0..10, 0..2]);
+        }
+
+        #[test]
+        pub fn test_reshape() {
+            let t = FloatTensor::zeros(&[4, 16]);
+            let reshaped = t.reshape(&[64]);
+
+            assert_eq!(reshaped.shape(), [64_usize]);
+            assert!(reshaped.is_contiguous());
+        }
+
+        #[test]
+        pub fn test_broadcast_to() {
+            let t = FloatTensor::fill(3f32, &[1, 4]);
+            let broadcast = t.broadcast_to(&[8, 4]);
+
+            assert_eq!(broadcast.shape(), [8_usize, 4_usize]);
+            assert_eq!(broadcast.strides()[0], 0);
+        }
+    }
+
+    mod reduce {
+        use crate::core::{Dimension, Factory, Reduce};
+        use crate::native::cpu::tensor::FloatTensor;
+
+        #[test]
+        #[should_panic(expected = "out of bounds")]
+        pub fn test_sum_rejects_out_of_bounds_axis() {
+            let t = FloatTensor::ones(&[4, 16]);
+            t.sum(&[5], false);
+        }
+
+        #[test]
+        pub fn test_sum_over_axis() {
+            let t = FloatTensor::ones(&[4, 16]);
+            let totals = t.sum(&[1], false);
+
+            assert_eq!(totals.shape(), [4_usize]);
+            assert_eq!(totals.data.as_slice(), [16f32; 4]);
+        }
+
+        #[test]
+        pub fn test_sum_keep_dims() {
+            let t = FloatTensor::ones(&[4, 16]);
+            let totals = t.sum(&[1], true);
+
+            assert_eq!(totals.shape(), [4_usize, 1_usize]);
+        }
+
+        #[test]
+        pub fn test_sum_all_axes() {
+            let t = FloatTensor::ones(&[4, 16]);
+            let total = t.sum(&[0, 1], false);
+
+            assert_eq!(total.numel(), 1);
+            assert_eq!(total.data.as_slice(), [64f32]);
+        }
+
+        #[test]
+        pub fn test_mean() {
+            let t = FloatTensor::fill(2f32, &[4, 16]);
+            let averages = t.mean(&[1], false);
+
+            assert_eq!(averages.data.as_slice(), [2f32; 4]);
+        }
+
+        #[test]
+        pub fn test_std_of_constant_is_zero() {
+            let t = FloatTensor::fill(2f32, &[4, 16]);
+            let deviations = t.std(&[1], false);
+
+            assert!(deviations.data.iter().all(|&v| v.abs() < f32::EPSILON));
+        }
+
+        #[test]
+        pub fn test_std_matches_known_value() {
+            let t = FloatTensor::arange(1f32, 5f32, 1f32).reshape(&[1, 4]);
+            let deviations = t.std(&[1], false);
+
+            assert!((deviations.data[0] - 1.1180340f32).abs() < 1e-5);
+        }
+
+        #[test]
+        pub fn test_min_max() {
+            let t = FloatTensor::arange(0f32, 16f32, 1f32).reshape(&[4, 4]);
+
+            let min = t.min(&[1], false);
+            assert_eq!(min.data.as_slice(), [0f32, 4f32, 8f32, 12f32]);
+
+            let max = t.max(&[1], false);
+            assert_eq!(max.data.as_slice(), [3f32, 7f32, 11f32, 15f32]);
+        }
+    }
+
+    mod safetensors {
+        use crate::core::{Dimension, Factory};
+        use crate::native::cpu::tensor::FloatTensor;
+
+        #[test]
+        pub fn test_save_load_round_trip() {
+            let path = std::env::temp_dir().join("mdarray_test_save_load_round_trip.safetensors");
+            let t = FloatTensor::fill(2f32, &[4, 16]);
+
+            t.save(&path, "weight").unwrap();
+            let loaded = FloatTensor::load(&path, "weight").unwrap();
+
+            assert_eq!(loaded.shape(), t.shape());
+            assert_eq!(loaded.data.as_slice(), t.data.as_slice());
+
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        #[test]
+        pub fn test_load_unknown_tensor_errors() {
+            let path = std::env::temp_dir().join("mdarray_test_load_unknown_tensor.safetensors");
+            let t = FloatTensor::fill(1f32, &[2, 2]);
+            t.save(&path, "weight").unwrap();
+
+            assert!(FloatTensor::load(&path, "missing").is_err());
+
+            std::fs::remove_file(&path).unwrap();
+        }
+
+        #[test]
+        pub fn test_load_truncated_file_errors_instead_of_panicking() {
+            let path = std::env::temp_dir().join("mdarray_test_load_truncated_file.safetensors");
+            let t = FloatTensor::fill(1f32, &[4, 4]);
+            t.save(&path, "weight").unwrap();
+
+            let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+            let truncated_len = file.metadata().unwrap().len() - 4;
+            file.set_len(truncated_len).unwrap();
+
+            assert!(FloatTensor::load(&path, "weight").is_err());
+
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+
+    mod access {
+        use crate::core::Factory;
+        use crate::native::cpu::tensor::FloatTensor;
+
+        #[test]
+        pub fn test_get_set() {
+            let mut t = FloatTensor::zeros(&[2, 3]);
+            assert_eq!(t.get(&[1, 2]), Some(0f32));
+
+            t.set(&[1, 2], 5f32);
+            assert_eq!(t.get(&[1, 2]), Some(5f32));
+            assert_eq!(t.get(&[0, 0]), Some(0f32));
+        }
+
+        #[test]
+        pub fn test_set_on_view_does_not_mutate_the_tensor_it_was_derived_from() {
+            let base = FloatTensor::zeros(&[2, 3]);
+            let mut row = base.slice(&[1..2, 0..3]);
+
+            row.set(&[0, 0], 1f32);
+
+            assert_eq!(row.get(&[0, 0]), Some(1f32));
+            assert_eq!(base.get(&[1, 0]), Some(0f32));
+        }
+
+        #[test]
+        pub fn test_get_out_of_bounds() {
+            let t = FloatTensor::zeros(&[2, 3]);
+            assert_eq!(t.get(&[2, 0]), None);
+            assert_eq!(t.get(&[0, 3]), None);
+        }
+
+        #[test]
+        pub fn test_flat_to_index() {
+            let t = FloatTensor::zeros(&[2, 3]);
+            assert_eq!(t.flat_to_index(0).as_slice(), [0, 0]);
+            assert_eq!(t.flat_to_index(4).as_slice(), [1, 1]);
+        }
+
+        #[test]
+        pub fn test_iter_respects_strides() {
+            let t = FloatTensor::arange(0f32, 6f32, 1f32).reshape(&[2, 3]);
+            let transposed = t.transpose(&[1, 0]);
+
+            assert_eq!(
+                transposed.iter().collect::<Vec<_>>(),
+                [0f32, 3f32, 1f32, 4f32, 2f32, 5f32]
+            );
+        }
+    }
+
+    mod linalg {
+        use crate::core::{Dimension, Factory};
+        use crate::native::cpu::tensor::FloatTensor;
+
+        #[test]
+        pub fn test_matmul_identity() {
+            let t = FloatTensor::arange(1f32, 5f32, 1f32).reshape(&[2, 2]);
+            let identity = FloatTensor::eye(2);
+
+            let product = t.matmul(&identity);
+            assert_eq!(product.iter().collect::<Vec<_>>(), [1f32, 2f32, 3f32, 4f32]);
+        }
+
+        #[test]
+        pub fn test_matmul_shape() {
+            let a = FloatTensor::ones(&[3, 4]);
+            let b = FloatTensor::ones(&[4, 5]);
+
+            let product = a.matmul(&b);
+            assert_eq!(product.shape(), [3_usize, 5_usize]);
+            assert!(product.iter().all(|v| (v - 4f32).abs() < f32::EPSILON));
+        }
+
+        #[test]
+        pub fn test_matmul_batched() {
+            let a = FloatTensor::ones(&[2, 3, 4]);
+            let b = FloatTensor::ones(&[2, 4, 5]);
+
+            let product = a.matmul(&b);
+            assert_eq!(product.shape(), [2_usize, 3_usize, 5_usize]);
+        }
+
+        #[test]
+        pub fn test_matmul_broadcasts_batch_dim() {
+            let weight = FloatTensor::fill(2f32, &[1, 4, 4]);
+            let batch = FloatTensor::fill(3f32, &[5, 4, 4]);
+
+            let product = weight.matmul(&batch);
+            assert_eq!(product.shape(), [5_usize, 4_usize, 4_usize]);
+            assert!(product.iter().all(|v| (v - 24f32).abs() < f32::EPSILON));
+        }
+
+        #[test]
+        pub fn test_matmul_empty_dimension_does_not_panic() {
+            let a = FloatTensor::zeros(&[0, 4]);
+            let b = FloatTensor::zeros(&[4, 5]);
+
+            let product = a.matmul(&b);
+            assert_eq!(product.shape(), [0_usize, 5_usize]);
+        }
+    }
 }